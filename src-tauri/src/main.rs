@@ -7,11 +7,31 @@ use std::time::Duration;
 use tauri::{Manager, State};
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
 
 #[derive(Default)]
 struct AppState {
     flask_process: Arc<Mutex<Option<std::process::Child>>>,
     ollama_process: Arc<Mutex<Option<std::process::Child>>>,
+    active_model: Arc<Mutex<Option<String>>>,
+    active_pulls: Arc<Mutex<HashMap<String, tokio::sync::oneshot::Sender<()>>>>,
+    ollama_config: Arc<Mutex<OllamaConfig>>,
+    model_states: Arc<Mutex<HashMap<String, ModelState>>>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct OllamaConfig {
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl Default for OllamaConfig {
+    fn default() -> Self {
+        OllamaConfig {
+            base_url: "http://localhost:11434".to_string(),
+            api_key: None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -19,6 +39,93 @@ struct ServiceStatus {
     flask_running: bool,
     ollama_running: bool,
     message: String,
+    model_states: HashMap<String, ModelState>,
+}
+
+// Per-model readiness reported by check_service_status.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+enum ModelState {
+    Stopped,
+    Starting,
+    Loading,
+    Ready,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ModelInfo {
+    name: String,
+    size: u64,
+    parameter_size: String,
+    modified_at: String,
+}
+
+#[derive(Deserialize)]
+struct OllamaTagsResponse {
+    models: Vec<OllamaModelEntry>,
+}
+
+#[derive(Deserialize)]
+struct OllamaModelEntry {
+    name: String,
+    size: u64,
+    modified_at: String,
+    #[serde(default)]
+    details: OllamaModelDetails,
+}
+
+#[derive(Deserialize, Default)]
+struct OllamaModelDetails {
+    #[serde(default)]
+    parameter_size: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ChatOptions {
+    #[serde(default = "default_num_ctx")]
+    num_ctx: u32,
+    #[serde(default)]
+    temperature: Option<f32>,
+}
+
+fn default_num_ctx() -> u32 {
+    4096
+}
+
+impl Default for ChatOptions {
+    fn default() -> Self {
+        ChatOptions {
+            num_ctx: default_num_ctx(),
+            temperature: None,
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+struct EmbeddingModel {
+    name: String,
+    dimension: usize,
+}
+
+#[derive(Serialize)]
+struct EmbeddingResult {
+    model: EmbeddingModel,
+    vectors: Vec<Vec<f32>>,
+}
+
+#[derive(Serialize, Clone)]
+struct PullProgress {
+    name: String,
+    status: String,
+    digest: Option<String>,
+    total: Option<u64>,
+    completed: Option<u64>,
+    percent: f64,
 }
 
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
@@ -27,30 +134,38 @@ fn greet(name: &str) -> String {
     format!("Hello, {}! Welcome to StudyCompanion!", name)
 }
 
+const DEFAULT_READINESS_DEADLINE_SECS: u64 = 30;
+
 #[tauri::command]
-async fn start_backend_services(state: State<'_, AppState>) -> Result<ServiceStatus, String> {
+async fn start_backend_services(
+    deadline_secs: Option<u64>,
+    state: State<'_, AppState>,
+) -> Result<ServiceStatus, String> {
     println!("Starting backend services...");
-    
-    // Start Ollama if not running
-    match check_ollama_running().await {
+
+    let config = state.ollama_config.lock().unwrap().clone();
+    let deadline = Duration::from_secs(deadline_secs.unwrap_or(DEFAULT_READINESS_DEADLINE_SECS));
+
+    // Start Ollama if not running. A remote endpoint is assumed to already be
+    // running and is never spawned locally.
+    let is_local = config.base_url.contains("localhost") || config.base_url.contains("127.0.0.1");
+    match check_ollama_running(&config).await {
         Ok(true) => println!("Ollama is already running"),
-        Ok(false) => {
+        Ok(false) if is_local => {
             println!("Starting Ollama...");
             start_ollama(&state).await?;
         }
+        Ok(false) => println!("Remote Ollama endpoint {} is not reachable", config.base_url),
         Err(e) => return Err(format!("Failed to check Ollama status: {}", e)),
     }
-    
+
     // Start Flask backend
     start_flask_backend(&state).await?;
-    
-    // Wait a moment for services to start
-    tokio::time::sleep(Duration::from_secs(3)).await;
-    
-    // Verify services are running
-    let flask_running = check_flask_running().await.unwrap_or(false);
-    let ollama_running = check_ollama_running().await.unwrap_or(false);
-    
+
+    // Poll both services with exponential backoff instead of a fixed sleep,
+    // returning as soon as both respond (or the deadline is hit).
+    let (flask_running, ollama_running) = wait_for_services_ready(&config, deadline).await;
+
     Ok(ServiceStatus {
         flask_running,
         ollama_running,
@@ -59,67 +174,502 @@ async fn start_backend_services(state: State<'_, AppState>) -> Result<ServiceSta
         } else {
             "Some services failed to start".to_string()
         },
+        model_states: state.model_states.lock().unwrap().clone(),
     })
 }
 
 #[tauri::command]
-async fn check_service_status() -> Result<ServiceStatus, String> {
+async fn check_service_status(state: State<'_, AppState>) -> Result<ServiceStatus, String> {
+    let config = state.ollama_config.lock().unwrap().clone();
     let flask_running = check_flask_running().await.unwrap_or(false);
-    let ollama_running = check_ollama_running().await.unwrap_or(false);
-    
+    let ollama_running = check_ollama_running(&config).await.unwrap_or(false);
+
     Ok(ServiceStatus {
         flask_running,
         ollama_running,
-        message: format!("Flask: {}, Ollama: {}", 
+        message: format!("Flask: {}, Ollama: {}",
                         if flask_running { "Running" } else { "Stopped" },
                         if ollama_running { "Running" } else { "Stopped" }),
+        model_states: state.model_states.lock().unwrap().clone(),
     })
 }
 
 #[tauri::command]
-async fn install_ollama_and_model() -> Result<String, String> {
+fn configure_ollama(
+    base_url: String,
+    api_key: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let mut config = state.ollama_config.lock().unwrap();
+    config.base_url = base_url.trim_end_matches('/').to_string();
+    config.api_key = api_key;
+
+    Ok(format!("Ollama endpoint set to {}", config.base_url))
+}
+
+#[tauri::command]
+async fn install_ollama_and_model(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
     println!("Installing Ollama and phi model...");
-    
+
+    let config = state.ollama_config.lock().unwrap().clone();
+
     // Check if Ollama is already installed
     if check_ollama_installed() {
         println!("Ollama is already installed");
-        
+
         // Check if phi model is available
-        if check_phi_model_available().await {
+        if check_phi_model_available(&config).await {
             return Ok("Ollama and phi model are already installed".to_string());
         } else {
             // Pull phi model
             println!("Pulling phi model...");
-            pull_phi_model().await?;
+            pull_phi_model(&app_handle, &state).await?;
             return Ok("Phi model installed successfully".to_string());
         }
     }
-    
+
     // Install Ollama based on platform
     install_ollama().await?;
-    
-    // Wait for installation to complete
-    tokio::time::sleep(Duration::from_secs(5)).await;
-    
+
+    // Poll until the freshly-installed Ollama actually answers instead of
+    // guessing how long installation takes to settle.
+    wait_for_ollama_ready(&config, Duration::from_secs(DEFAULT_READINESS_DEADLINE_SECS)).await;
+
     // Pull phi model
     println!("Pulling phi model...");
-    pull_phi_model().await?;
-    
+    pull_phi_model(&app_handle, &state).await?;
+
     Ok("Ollama and phi model installed successfully".to_string())
 }
 
+#[tauri::command]
+async fn list_models(state: State<'_, AppState>) -> Result<Vec<ModelInfo>, String> {
+    let config = state.ollama_config.lock().unwrap().clone();
+    let client = reqwest::Client::new();
+    let response = apply_auth(client.get(format!("{}/api/tags", config.base_url)), &config)
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Ollama: {}", e))?;
+
+    let tags: OllamaTagsResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Ollama model list: {}", e))?;
+
+    Ok(tags
+        .models
+        .into_iter()
+        .map(|m| ModelInfo {
+            name: m.name,
+            size: m.size,
+            parameter_size: m.details.parameter_size,
+            modified_at: m.modified_at,
+        })
+        .collect())
+}
+
+#[tauri::command]
+async fn pull_model(
+    name: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    stream_pull_model(&app_handle, &state, &name).await?;
+    Ok(format!("Model {} pulled successfully", name))
+}
+
+// Keyed per model name, not a single process-wide flag, so cancelling one
+// download can't also cancel an unrelated one in flight.
+#[tauri::command]
+fn cancel_pull(name: String, state: State<'_, AppState>) -> Result<String, String> {
+    let mut active_pulls = state.active_pulls.lock().unwrap();
+    match active_pulls.remove(&name) {
+        Some(cancel_tx) => {
+            let _ = cancel_tx.send(());
+            Ok(format!("Cancelling pull of {}", name))
+        }
+        None => Err(format!("No pull in progress for {}", name)),
+    }
+}
+
+async fn stream_pull_model(
+    app_handle: &tauri::AppHandle,
+    state: &State<'_, AppState>,
+    name: &str,
+) -> Result<(), String> {
+    let config = state.ollama_config.lock().unwrap().clone();
+
+    let (cancel_tx, mut cancel_rx) = tokio::sync::oneshot::channel();
+    {
+        let mut active_pulls = state.active_pulls.lock().unwrap();
+        active_pulls.insert(name.to_string(), cancel_tx);
+    }
+
+    let result = run_pull_stream(app_handle, &config, name, &mut cancel_rx).await;
+
+    state.active_pulls.lock().unwrap().remove(name);
+
+    result
+}
+
+async fn run_pull_stream(
+    app_handle: &tauri::AppHandle,
+    config: &OllamaConfig,
+    name: &str,
+    cancel_rx: &mut tokio::sync::oneshot::Receiver<()>,
+) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let mut response = apply_auth(client.post(format!("{}/api/pull", config.base_url)), config)
+        .json(&serde_json::json!({ "name": name, "stream": true }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to start pull for {}: {}", name, e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Failed to pull {}: {} {}", name, status, body));
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+    loop {
+        let chunk = tokio::select! {
+            chunk = response.chunk() => {
+                chunk.map_err(|e| format!("Failed to read pull stream for {}: {}", name, e))?
+            }
+            _ = &mut *cancel_rx => return Err(format!("Pull of {} was cancelled", name)),
+        };
+
+        let chunk = match chunk {
+            Some(chunk) => chunk,
+            None => break,
+        };
+
+        buffer.extend_from_slice(&chunk);
+
+        for line in drain_complete_lines(&mut buffer) {
+            let parsed: serde_json::Value = serde_json::from_str(&line)
+                .map_err(|e| format!("Failed to parse pull progress for {}: {}", name, e))?;
+
+            if let Some(err) = parsed.get("error").and_then(|v| v.as_str()) {
+                return Err(format!("Failed to pull {}: {}", name, err));
+            }
+
+            let total = parsed.get("total").and_then(|v| v.as_u64());
+            let completed = parsed.get("completed").and_then(|v| v.as_u64());
+            let percent = match (total, completed) {
+                (Some(total), Some(completed)) if total > 0 => {
+                    (completed as f64 / total as f64) * 100.0
+                }
+                _ => 0.0,
+            };
+
+            let progress = PullProgress {
+                name: name.to_string(),
+                status: parsed
+                    .get("status")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                digest: parsed
+                    .get("digest")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+                total,
+                completed,
+                percent,
+            };
+
+            let _ = app_handle.emit_all("model-pull-progress", progress);
+        }
+    }
+
+    Ok(())
+}
+
+// Pulls complete newline-terminated lines out of a growing byte buffer,
+// decoding each only once all of its bytes have arrived. Decoding each
+// network chunk independently would corrupt any multi-byte UTF-8 character
+// split across a chunk boundary.
+fn drain_complete_lines(buffer: &mut Vec<u8>) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+        let line_bytes: Vec<u8> = buffer.drain(..=pos).collect();
+        let line = String::from_utf8_lossy(&line_bytes[..line_bytes.len() - 1])
+            .trim()
+            .to_string();
+
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+#[tauri::command]
+async fn delete_model(name: String, state: State<'_, AppState>) -> Result<String, String> {
+    let config = state.ollama_config.lock().unwrap().clone();
+    let client = reqwest::Client::new();
+    let response = apply_auth(
+        client.delete(format!("{}/api/delete", config.base_url)),
+        &config,
+    )
+    .json(&serde_json::json!({ "name": name }))
+    .timeout(Duration::from_secs(5))
+    .send()
+    .await
+    .map_err(|e| format!("Failed to reach Ollama: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to delete model {}: {}", name, response.status()));
+    }
+
+    Ok(format!("Model {} deleted successfully", name))
+}
+
+#[tauri::command]
+async fn chat_completion(
+    model: String,
+    messages: Vec<ChatMessage>,
+    options: Option<ChatOptions>,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let config = state.ollama_config.lock().unwrap().clone();
+    let options = options.unwrap_or_default();
+
+    let client = reqwest::Client::new();
+    let mut response = apply_auth(client.post(format!("{}/api/chat", config.base_url)), &config)
+        .json(&serde_json::json!({
+            "model": model,
+            "messages": messages,
+            "stream": true,
+            "options": {
+                "num_ctx": options.num_ctx,
+                "temperature": options.temperature,
+            }
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to start chat completion: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Chat completion failed: {} {}", status, body));
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut full_reply = String::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed to read chat stream: {}", e))?
+    {
+        buffer.extend_from_slice(&chunk);
+
+        for line in drain_complete_lines(&mut buffer) {
+            let parsed: serde_json::Value = serde_json::from_str(&line)
+                .map_err(|e| format!("Failed to parse chat chunk: {}", e))?;
+
+            if let Some(err) = parsed.get("error").and_then(|v| v.as_str()) {
+                return Err(format!("Chat completion failed: {}", err));
+            }
+
+            let token = parsed
+                .get("message")
+                .and_then(|m| m.get("content"))
+                .and_then(|c| c.as_str())
+                .unwrap_or("");
+            let done = parsed.get("done").and_then(|d| d.as_bool()).unwrap_or(false);
+
+            full_reply.push_str(token);
+
+            let _ = app_handle.emit_all(
+                "chat-token",
+                serde_json::json!({ "token": token, "done": done }),
+            );
+        }
+    }
+
+    Ok(full_reply)
+}
+
+const EMBED_MAX_ATTEMPTS: u32 = 3;
+
+#[tauri::command]
+async fn embed_texts(
+    model: String,
+    inputs: Vec<String>,
+    state: State<'_, AppState>,
+) -> Result<EmbeddingResult, String> {
+    let config = state.ollama_config.lock().unwrap().clone();
+    let client = reqwest::Client::new();
+
+    let mut vectors = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        vectors.push(embed_one(&client, &config, &model, input).await?);
+    }
+
+    let dimension = vectors.first().map(|v| v.len()).unwrap_or(0);
+
+    Ok(EmbeddingResult {
+        model: EmbeddingModel { name: model, dimension },
+        vectors,
+    })
+}
+
+async fn embed_one(
+    client: &reqwest::Client,
+    config: &OllamaConfig,
+    model: &str,
+    input: &str,
+) -> Result<Vec<f32>, String> {
+    let mut last_error = String::new();
+
+    for attempt in 0..EMBED_MAX_ATTEMPTS {
+        let response = apply_auth(
+            client.post(format!("{}/api/embeddings", config.base_url)),
+            config,
+        )
+        .json(&serde_json::json!({ "model": model, "prompt": input }))
+        .timeout(Duration::from_secs(30))
+        .send()
+        .await;
+
+        match response {
+            Ok(response) if response.status().is_success() => {
+                let parsed: serde_json::Value = response
+                    .json()
+                    .await
+                    .map_err(|e| format!("Failed to parse embedding response: {}", e))?;
+
+                return match parsed.get("embedding").and_then(|v| v.as_array()) {
+                    Some(values) => Ok(values
+                        .iter()
+                        .filter_map(|v| v.as_f64())
+                        .map(|v| v as f32)
+                        .collect()),
+                    None => Err(format!(
+                        "Ollama returned no embedding for model '{}'. Is it pulled?",
+                        model
+                    )),
+                };
+            }
+            Ok(response) if response.status() == reqwest::StatusCode::NOT_FOUND => {
+                return Err(format!(
+                    "Embedding model '{}' isn't pulled yet. Run `ollama pull {}` first.",
+                    model, model
+                ));
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                last_error = format!("Ollama returned {}: {}", status, body);
+            }
+            Err(e) if e.is_timeout() || e.is_connect() => {
+                last_error = format!("Transient error reaching Ollama: {}", e);
+            }
+            Err(e) => return Err(format!("Failed to request embedding: {}", e)),
+        }
+
+        if attempt + 1 < EMBED_MAX_ATTEMPTS {
+            tokio::time::sleep(Duration::from_millis(250 * (attempt as u64 + 1))).await;
+        }
+    }
+
+    Err(format!(
+        "Failed to embed text after {} attempts: {}",
+        EMBED_MAX_ATTEMPTS, last_error
+    ))
+}
+
+// Forces a model into memory with an empty-prompt generate request.
+#[tauri::command]
+async fn preload_model(
+    name: String,
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    set_model_state(&app_handle, &state, &name, ModelState::Starting);
+
+    let config = state.ollama_config.lock().unwrap().clone();
+    set_model_state(&app_handle, &state, &name, ModelState::Loading);
+
+    let client = reqwest::Client::new();
+    let response = apply_auth(client.post(format!("{}/api/generate", config.base_url)), &config)
+        .json(&serde_json::json!({ "model": name, "prompt": "", "stream": false }))
+        .timeout(Duration::from_secs(120))
+        .send()
+        .await;
+
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            set_model_state(&app_handle, &state, &name, ModelState::Stopped);
+            return Err(format!("Failed to preload model {}: {}", name, e));
+        }
+    };
+
+    if !response.status().is_success() {
+        set_model_state(&app_handle, &state, &name, ModelState::Stopped);
+        return Err(format!("Failed to preload model {}: {}", name, response.status()));
+    }
+
+    set_model_state(&app_handle, &state, &name, ModelState::Ready);
+
+    Ok(format!("Model {} is ready", name))
+}
+
+fn set_model_state(
+    app_handle: &tauri::AppHandle,
+    state: &State<'_, AppState>,
+    name: &str,
+    new_state: ModelState,
+) {
+    {
+        let mut states = state.model_states.lock().unwrap();
+        states.insert(name.to_string(), new_state.clone());
+    }
+
+    let _ = app_handle.emit_all(
+        "model-state-changed",
+        serde_json::json!({ "name": name, "state": new_state }),
+    );
+}
+
+#[tauri::command]
+async fn set_active_model(name: String, state: State<'_, AppState>) -> Result<String, String> {
+    let mut active_model = state.active_model.lock().unwrap();
+    *active_model = Some(name.clone());
+
+    Ok(format!("Active model set to {}", name))
+}
+
 async fn start_ollama(state: &State<'_, AppState>) -> Result<(), String> {
-    let mut process = Command::new("ollama")
+    let process = Command::new("ollama")
         .arg("serve")
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .spawn()
-        .map_err(|e| format!("Failed to start Ollama: {}", e))?;
-    
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                "Ollama executable not found. Install Ollama and make sure it's on your PATH.".to_string()
+            } else {
+                format!("Failed to start Ollama: {}", e)
+            }
+        })?;
+
     // Store the process handle
     let mut ollama_process = state.ollama_process.lock().unwrap();
     *ollama_process = Some(process);
-    
+
     Ok(())
 }
 
@@ -130,13 +680,13 @@ async fn start_flask_backend(state: &State<'_, AppState>) -> Result<(), String>
         .parent()
         .ok_or("Failed to get parent directory")?
         .join("backend");
-    
+
     let flask_script = resource_dir.join("app.py");
-    
+
     if !flask_script.exists() {
         return Err("Backend script not found. Make sure app.py is bundled with the application.".to_string());
     }
-    
+
     // Start Flask backend
     let process = Command::new("python")
         .arg(flask_script)
@@ -145,15 +695,83 @@ async fn start_flask_backend(state: &State<'_, AppState>) -> Result<(), String>
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .spawn()
-        .map_err(|e| format!("Failed to start Flask backend: {}", e))?;
-    
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                "Python executable not found. Install Python and make sure it's on your PATH.".to_string()
+            } else {
+                format!("Failed to start Flask backend: {}", e)
+            }
+        })?;
+
     // Store the process handle
     let mut flask_process = state.flask_process.lock().unwrap();
     *flask_process = Some(process);
-    
+
     Ok(())
 }
 
+#[tauri::command]
+async fn restart_service(which: String, state: State<'_, AppState>) -> Result<String, String> {
+    match which.as_str() {
+        "flask" => {
+            {
+                let mut flask_process = state.flask_process.lock().unwrap();
+                if let Some(mut process) = flask_process.take() {
+                    let _ = process.kill();
+                }
+            }
+            start_flask_backend(&state).await?;
+            Ok("Flask backend restarted".to_string())
+        }
+        "ollama" => {
+            {
+                let mut ollama_process = state.ollama_process.lock().unwrap();
+                if let Some(mut process) = ollama_process.take() {
+                    let _ = process.kill();
+                }
+            }
+            start_ollama(&state).await?;
+            Ok("Ollama restarted".to_string())
+        }
+        other => Err(format!("Unknown service '{}'. Expected 'flask' or 'ollama'.", other)),
+    }
+}
+
+// Polls Flask and Ollama with exponential backoff instead of a fixed sleep.
+async fn wait_for_services_ready(config: &OllamaConfig, deadline: Duration) -> (bool, bool) {
+    let start = std::time::Instant::now();
+    let mut backoff = Duration::from_millis(200);
+
+    loop {
+        let flask_running = check_flask_running().await.unwrap_or(false);
+        let ollama_running = check_ollama_running(config).await.unwrap_or(false);
+
+        if (flask_running && ollama_running) || start.elapsed() >= deadline {
+            return (flask_running, ollama_running);
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(Duration::from_secs(2));
+    }
+}
+
+async fn wait_for_ollama_ready(config: &OllamaConfig, deadline: Duration) -> bool {
+    let start = std::time::Instant::now();
+    let mut backoff = Duration::from_millis(200);
+
+    loop {
+        if check_ollama_running(config).await.unwrap_or(false) {
+            return true;
+        }
+        if start.elapsed() >= deadline {
+            return false;
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(Duration::from_secs(2));
+    }
+}
+
 async fn check_flask_running() -> Result<bool, reqwest::Error> {
     let client = reqwest::Client::new();
     match client
@@ -167,10 +785,9 @@ async fn check_flask_running() -> Result<bool, reqwest::Error> {
     }
 }
 
-async fn check_ollama_running() -> Result<bool, reqwest::Error> {
+async fn check_ollama_running(config: &OllamaConfig) -> Result<bool, reqwest::Error> {
     let client = reqwest::Client::new();
-    match client
-        .get("http://localhost:11434/api/tags")
+    match apply_auth(client.get(format!("{}/api/tags", config.base_url)), config)
         .timeout(Duration::from_secs(5))
         .send()
         .await
@@ -187,10 +804,9 @@ fn check_ollama_installed() -> bool {
         .is_ok()
 }
 
-async fn check_phi_model_available() -> bool {
+async fn check_phi_model_available(config: &OllamaConfig) -> bool {
     let client = reqwest::Client::new();
-    match client
-        .get("http://localhost:11434/api/tags")
+    match apply_auth(client.get(format!("{}/api/tags", config.base_url)), config)
         .timeout(Duration::from_secs(5))
         .send()
         .await
@@ -206,6 +822,14 @@ async fn check_phi_model_available() -> bool {
     }
 }
 
+// Attaches an Authorization header when the config carries an API key.
+fn apply_auth(builder: reqwest::RequestBuilder, config: &OllamaConfig) -> reqwest::RequestBuilder {
+    match &config.api_key {
+        Some(key) if !key.is_empty() => builder.header("Authorization", format!("Bearer {}", key)),
+        _ => builder,
+    }
+}
+
 async fn install_ollama() -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
@@ -256,19 +880,8 @@ async fn install_ollama() -> Result<(), String> {
     Ok(())
 }
 
-async fn pull_phi_model() -> Result<(), String> {
-    let output = Command::new("ollama")
-        .arg("pull")
-        .arg("phi")
-        .output()
-        .map_err(|e| format!("Failed to pull phi model: {}", e))?;
-    
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Failed to pull phi model: {}", error));
-    }
-    
-    Ok(())
+async fn pull_phi_model(app_handle: &tauri::AppHandle, state: &State<'_, AppState>) -> Result<(), String> {
+    stream_pull_model(app_handle, state, "phi").await
 }
 
 fn main() {
@@ -278,21 +891,30 @@ fn main() {
             greet,
             start_backend_services,
             check_service_status,
-            install_ollama_and_model
+            install_ollama_and_model,
+            list_models,
+            pull_model,
+            delete_model,
+            set_active_model,
+            cancel_pull,
+            configure_ollama,
+            chat_completion,
+            embed_texts,
+            preload_model,
+            restart_service
         ])
         .setup(|app| {
             let app_handle = app.handle();
-            
-            // Start backend services on app startup
+
+            // Start backend services on app startup. start_backend_services
+            // polls each service's readiness endpoint itself, so there's no
+            // fixed delay to wait out here.
             tauri::async_runtime::spawn(async move {
                 println!("Starting StudyCompanion...");
-                
-                // Wait a moment for the app to fully initialize
-                tokio::time::sleep(Duration::from_secs(2)).await;
-                
+
                 // Get the app state
                 if let Some(state) = app_handle.try_state::<AppState>() {
-                    match start_backend_services(state).await {
+                    match start_backend_services(None, state).await {
                         Ok(status) => println!("Backend services status: {}", status.message),
                         Err(e) => eprintln!("Failed to start backend services: {}", e),
                     }